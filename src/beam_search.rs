@@ -0,0 +1,178 @@
+use std::cmp;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+
+use crate::pathfinding;
+use crate::route;
+use crate::simulate;
+use crate::threat;
+use crate::time_keeper::TimeKeeper;
+use crate::{ChooseCharacter, Direction, Game, Move, AI, TIME_SLACK_MILLIS};
+
+// How many candidate states survive each depth of the search.
+const BEAM_WIDTH: usize = 8;
+
+// How many moves ahead to plan before settling on an answer.
+const SEARCH_DEPTH: usize = 6;
+
+// How long a dropped bomb takes to explode.
+const DROP_BOMB_ROUNDS: usize = 5;
+
+const SNIPPET_BANKED_WEIGHT: i64 = 1000;
+const BOMB_WEIGHT: i64 = 10;
+const DANGER_PENALTY: i64 = 10_000;
+
+struct Candidate {
+  game: Game,
+  first_move: Move,
+  score: i64,
+}
+
+/// Plans several moves ahead with beam search: expand every legal move from
+/// each surviving state, score the results, and keep only the `BEAM_WIDTH`
+/// best per depth. Stops at `SEARCH_DEPTH` or once the turn's `TimeKeeper`
+/// budget runs out, whichever comes first, and returns the first move of the
+/// best sequence seen so far.
+pub(crate) struct BeamSearch;
+
+impl AI for BeamSearch {
+  fn action_character(&mut self, _time: usize) -> ChooseCharacter {
+    ChooseCharacter::Bixie
+  }
+
+  fn action_move(&mut self, game: &Game, time: usize) -> Move {
+    let budget = cmp::min(game.settings.time_per_move as u64 + TIME_SLACK_MILLIS, time as u64);
+    let time_keeper = TimeKeeper::new(budget);
+
+    if time_keeper.is_over() {
+      return Move::Pass;
+    }
+
+    let danger = threat::danger_map(&game.field, SEARCH_DEPTH + 1);
+    let route_target = route::plan_route(&game.field, &time_keeper).first().cloned();
+
+    let mut beam: Vec<Candidate> = legal_moves(game)
+      .into_iter()
+      .map(|mv| {
+        let next = simulate::simulate(game, &mv);
+        let score = score_state(&next, &danger, route_target, 1);
+        Candidate { game: next, first_move: mv, score }
+      })
+      .collect();
+    beam.sort_by(compare_candidates);
+    beam.truncate(BEAM_WIDTH);
+
+    let mut best = beam.first().map_or(Move::Pass, |c| c.first_move.clone());
+    let mut best_score = beam.first().map_or(i64::min_value(), |c| c.score);
+
+    for depth in 2..=SEARCH_DEPTH {
+      if time_keeper.is_over() || beam.is_empty() {
+        break;
+      }
+
+      let mut next_beam = Vec::new();
+
+      for candidate in &beam {
+        if time_keeper.is_over() {
+          break;
+        }
+
+        for mv in legal_moves(&candidate.game) {
+          let next_game = simulate::simulate(&candidate.game, &mv);
+          let score = score_state(&next_game, &danger, route_target, depth);
+          next_beam.push(Candidate { game: next_game, first_move: candidate.first_move.clone(), score });
+        }
+      }
+
+      if next_beam.is_empty() {
+        break;
+      }
+
+      next_beam.sort_by(compare_candidates);
+      next_beam.truncate(BEAM_WIDTH);
+
+      if next_beam[0].score > best_score {
+        best_score = next_beam[0].score;
+        best = next_beam[0].first_move.clone();
+      }
+
+      beam = next_beam;
+    }
+
+    best
+  }
+}
+
+// Higher score first; Vec::sort_by is stable, and candidates are always
+// generated in the same fixed move order, so ties resolve deterministically.
+fn compare_candidates(a: &Candidate, b: &Candidate) -> Ordering {
+  b.score.cmp(&a.score)
+}
+
+fn score_state(game: &Game,
+                danger: &[HashSet<(usize, usize)>],
+                route_target: Option<(usize, usize)>,
+                round: usize)
+                -> i64 {
+  let player = game.players.get(&game.settings.my_bot);
+  let snippets_banked = player.map_or(0, |p| p.snippets()) as i64;
+  let bombs = player.map_or(0, |p| p.bombs()) as i64;
+  let distance_to_target = distance_to_route_target(game, route_target);
+  // `usize::max_value()` (see `distance_to_route_target`) would silently
+  // become -1 under a bitwise `as i64` cast and flip the penalty into a
+  // bonus, so the sentinel is special-cased before the cast and the
+  // combination is built with saturating arithmetic to avoid overflow.
+  let distance_penalty =
+    if distance_to_target == usize::max_value() { i64::max_value() } else { distance_to_target as i64 };
+
+  let in_danger = danger.get(round).map_or(false, |cells| cells.contains(&game.field.me));
+  let danger_penalty = if in_danger { DANGER_PENALTY } else { 0 };
+
+  (snippets_banked * SNIPPET_BANKED_WEIGHT + bombs * BOMB_WEIGHT)
+    .saturating_sub(distance_penalty)
+    .saturating_sub(danger_penalty)
+}
+
+// Distance to the first stop of the snippet-collection route planned up
+// front for this turn, so the beam favors progress along an efficient tour
+// over greedily chasing whichever snippet happens to be closest this
+// instant. The route is fixed for the whole search, not replanned per node:
+// `plan_route` runs its own 2-opt pass and is far too costly to call once
+// per candidate state. Returns 0 when there's no target to chase, and
+// `usize::max_value()` when there is one but it's unreachable — the same
+// unreachable-node sentinel `threat.rs` uses.
+fn distance_to_route_target(game: &Game, route_target: Option<(usize, usize)>) -> usize {
+  match route_target {
+    None => 0,
+    Some(target) => {
+      pathfinding::shortest_path(&game.field, game.field.me, target).map_or(usize::max_value(), |path| path.len())
+    }
+  }
+}
+
+fn legal_moves(game: &Game) -> Vec<Move> {
+  let directions = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+  let mut moves = vec![Move::Pass];
+
+  for &direction in &directions {
+    if is_legal_step(game, direction) {
+      moves.push(Move::Direction { direction });
+    }
+  }
+
+  let has_bombs = game.players.get(&game.settings.my_bot).map_or(false, |p| p.bombs() > 0);
+  if has_bombs {
+    for &direction in &directions {
+      if is_legal_step(game, direction) {
+        moves.push(Move::DropBomb { direction, rounds: DROP_BOMB_ROUNDS });
+      }
+    }
+  }
+
+  moves
+}
+
+fn is_legal_step(game: &Game, direction: Direction) -> bool {
+  pathfinding::step(&game.field, game.field.me, direction)
+    .map_or(false, |coords| pathfinding::is_open(&game.field, coords))
+}
@@ -2,15 +2,22 @@ use std::collections::HashMap;
 use std::io;
 use std::fmt;
 
-#[derive(Debug, Default)]
-struct Settings {
+mod beam_search;
+mod pathfinding;
+mod route;
+mod simulate;
+mod threat;
+mod time_keeper;
+
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Settings {
   timebank: usize,
-  time_per_move: usize,
+  pub(crate) time_per_move: usize,
   player_names: Vec<String>,
-  my_bot: String,
-  my_bot_id: usize,
-  field_width: usize,
-  field_height: usize,
+  pub(crate) my_bot: String,
+  pub(crate) my_bot_id: usize,
+  pub(crate) field_width: usize,
+  pub(crate) field_height: usize,
   max_rounds: usize,
 }
 
@@ -34,12 +41,12 @@ impl Settings {
   }
 }
 
-#[derive(Debug, Default)]
-struct Game {
-  settings: Settings,
-  round: usize,
-  field: Field,
-  players: HashMap<String, Player>,
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Game {
+  pub(crate) settings: Settings,
+  pub(crate) round: usize,
+  pub(crate) field: Field,
+  pub(crate) players: HashMap<String, Player>,
 }
 
 impl Game {
@@ -67,15 +74,15 @@ impl Game {
   }
 }
 
-#[derive(Debug, Default)]
-struct Field {
-  cells: Vec<Vec<Cell>>,
-  snippets: Vec<(usize, usize)>,
-  me: (usize, usize),
-  others: Vec<(usize, usize)>
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Field {
+  pub(crate) cells: Vec<Vec<Cell>>,
+  pub(crate) snippets: Vec<(usize, usize)>,
+  pub(crate) me: (usize, usize),
+  pub(crate) others: Vec<(usize, usize)>
 }
 
-fn parse_field(settings: &Settings, field: &str) -> Field {
+pub(crate) fn parse_field(settings: &Settings, field: &str) -> Field {
   let parsed_cells: Vec<_> = field.split(",")
     .map(|cell| parse_cell(cell))
     .collect();
@@ -107,19 +114,96 @@ fn parse_field(settings: &Settings, field: &str) -> Field {
   }
 }
 
+// How many rounds a bug spawn point waits before releasing its next bug,
+// once it has already fired. The field only ever tells us the countdown to
+// the *first* spawn, so this is our best guess at the recurring cadence.
+const BUG_RESPAWN_INTERVAL_ROUNDS: usize = 20;
+
 #[derive(Clone, Debug)]
-struct Cell {
+pub(crate) struct Cell {
   types: Vec<CellType>,
 }
 
 impl Cell {
-  fn has_code_snippet(&self) -> bool {
+  pub(crate) fn has_code_snippet(&self) -> bool {
     self.types.iter().cloned().find(|t| t == &CellType::CodeSnippet).is_some()
   }
 
   fn player_ids(&self) -> Vec<usize> {
     self.types.iter().cloned().filter_map(|t| t.player_id()).collect()
   }
+
+  pub(crate) fn bug_ai_types(&self) -> Vec<usize> {
+    self.types.iter().cloned().filter_map(|t| t.bug_ai_type()).collect()
+  }
+
+  pub(crate) fn is_inaccessible(&self) -> bool {
+    self.types.iter().cloned().find(|t| t == &CellType::Inaccessible).is_some()
+  }
+
+  pub(crate) fn has_bug(&self) -> bool {
+    self.types.iter().cloned().find(|t| matches!(t, CellType::Bug { .. })).is_some()
+  }
+
+  pub(crate) fn has_mine(&self) -> bool {
+    self.types.iter().cloned().find(|t| matches!(t, CellType::Mine { .. })).is_some()
+  }
+
+  pub(crate) fn is_gate_left(&self) -> bool {
+    self.types.iter().cloned().find(|t| t == &CellType::GateLeft).is_some()
+  }
+
+  pub(crate) fn is_gate_right(&self) -> bool {
+    self.types.iter().cloned().find(|t| t == &CellType::GateRight).is_some()
+  }
+
+  pub(crate) fn remove_code_snippet(&mut self) {
+    self.types.retain(|t| t != &CellType::CodeSnippet);
+  }
+
+  pub(crate) fn remove_player(&mut self, id: usize) {
+    self.types.retain(|t| t.player_id() != Some(id));
+  }
+
+  pub(crate) fn add_player(&mut self, id: usize) {
+    self.types.push(CellType::Player { id });
+  }
+
+  pub(crate) fn place_mine(&mut self, rounds_before_explode: usize) {
+    self.types.push(CellType::Mine { rounds_before_explode });
+  }
+
+  /// Decrements this cell's mine/spawn-point timers by one round and reports
+  /// whether a bug spawn point just reached zero and should release a bug.
+  /// Firing resets the spawn point's timer so the next spawn is a fresh
+  /// countdown rather than firing again on every following round.
+  pub(crate) fn tick_timers(&mut self) -> bool {
+    let mut spawn = false;
+
+    for cell_type in self.types.iter_mut() {
+      match *cell_type {
+        CellType::Mine { ref mut rounds_before_explode } if *rounds_before_explode > 0 => {
+          *rounds_before_explode -= 1;
+        }
+        CellType::BugSpawnPoint { ref mut rounds_before_spawn } => {
+          if *rounds_before_spawn > 0 {
+            *rounds_before_spawn -= 1;
+          }
+          if *rounds_before_spawn == 0 {
+            spawn = true;
+            *rounds_before_spawn = BUG_RESPAWN_INTERVAL_ROUNDS;
+          }
+        }
+        _ => {}
+      }
+    }
+
+    spawn
+  }
+
+  pub(crate) fn spawn_bug(&mut self, ai_type: usize) {
+    self.types.push(CellType::Bug { ai_type });
+  }
 }
 
 fn parse_cell(cell: &str) -> Cell {
@@ -148,6 +232,14 @@ impl CellType {
       None
     }
   }
+
+  fn bug_ai_type(&self) -> Option<usize> {
+    if let &CellType::Bug { ai_type } = self {
+      Some(ai_type)
+    } else {
+      None
+    }
+  }
 }
 
 fn parse_cell_type(cell_type: &str) -> CellType {
@@ -171,10 +263,10 @@ fn parse_cell_type(cell_type: &str) -> CellType {
   }
 }
 
-#[derive(Debug, Default)]
-struct Player {
-  snippets: usize,
-  bombs: usize,
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Player {
+  pub(crate) snippets: usize,
+  pub(crate) bombs: usize,
 }
 
 impl Player {
@@ -185,10 +277,32 @@ impl Player {
       _ => {}
     }
   }
+
+  pub(crate) fn collect_snippet(&mut self) {
+    self.snippets += 1;
+  }
+
+  pub(crate) fn snippets(&self) -> usize {
+    self.snippets
+  }
+
+  pub(crate) fn bombs(&self) -> usize {
+    self.bombs
+  }
+
+  /// Spends one bomb if the player has any; returns whether one was spent.
+  pub(crate) fn use_bomb(&mut self) -> bool {
+    if self.bombs > 0 {
+      self.bombs -= 1;
+      true
+    } else {
+      false
+    }
+  }
 }
 
 #[derive(Debug)]
-enum ChooseCharacter {
+pub(crate) enum ChooseCharacter {
   Bixie,
   Bixiette,
 }
@@ -204,8 +318,8 @@ impl fmt::Display for ChooseCharacter {
   }
 }
 
-#[derive(Debug)]
-enum Direction {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum Direction {
   Up,
   Down,
   Left,
@@ -225,8 +339,8 @@ impl fmt::Display for Direction {
   }
 }
 
-#[derive(Debug)]
-enum Move {
+#[derive(Debug, Clone)]
+pub(crate) enum Move {
   Direction { direction: Direction },
   DropBomb { direction: Direction, rounds: usize },
   Pass,
@@ -244,26 +358,18 @@ impl fmt::Display for Move {
   }
 }
 
-trait AI {
+pub(crate) trait AI {
   fn action_character(&mut self, time: usize) -> ChooseCharacter;
   fn action_move(&mut self, game: &Game, time: usize) -> Move;
 }
 
-struct Basic;
-
-impl AI for Basic {
-  fn action_character(&mut self, _time: usize) -> ChooseCharacter {
-    ChooseCharacter::Bixie
-  }
-
-  fn action_move(&mut self, game: &Game, _time: usize) -> Move {
-    Move::Pass
-  }
-}
+// Extra time (in millis) a turn may spend on top of `time_per_move`, drawn
+// from the timebank; the turn's budget is still capped at the time remaining.
+pub(crate) const TIME_SLACK_MILLIS: u64 = 50;
 
 fn main() {
   let mut game: Game = Default::default();
-  let mut ai = Basic;
+  let mut ai = beam_search::BeamSearch;
 
   let stdin = io::stdin();
   loop {
@@ -287,3 +393,28 @@ fn main() {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn tick_timers_spawns_a_bug_exactly_once_per_expiry() {
+    let mut cell = Cell { types: vec![CellType::BugSpawnPoint { rounds_before_spawn: 0 }] };
+
+    assert!(cell.tick_timers(), "a spawn point at zero should fire");
+    assert!(!cell.tick_timers(), "it must not fire again until its timer expires again");
+  }
+
+  #[test]
+  fn shortest_path_wraps_through_a_gate_pair() {
+    let settings = Settings { field_width: 3, field_height: 1, ..Default::default() };
+    // A gate pair with an inaccessible cell between them: the only way from
+    // the left gate to the right gate is to wrap around, not cross the
+    // middle.
+    let field = parse_field(&settings, "Gl,x,Gr");
+
+    let path = pathfinding::shortest_path(&field, (0, 0), (0, 2));
+    assert_eq!(path, Some(vec![Direction::Left]));
+  }
+}
@@ -0,0 +1,136 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use crate::{Direction, Field};
+
+/// Shortest path between two cells via BFS on the 4-connected grid, honoring
+/// gate wraparound (`GateLeft` <-> `GateRight`) and treating inaccessible or
+/// bug-occupied cells as walls. Returns `None` when `to` can't be reached.
+pub(crate) fn shortest_path(field: &Field, from: (usize, usize), to: (usize, usize)) -> Option<Vec<Direction>> {
+  shortest_path_ignoring(field, from, to, None)
+}
+
+/// Like `shortest_path`, but treats `ignore` (if given) as open even if it's
+/// bug-occupied. Used to project a single bug's own future path without
+/// that bug's frozen starting cell blocking itself for the rest of the
+/// horizon.
+pub(crate) fn shortest_path_ignoring(field: &Field,
+                                      from: (usize, usize),
+                                      to: (usize, usize),
+                                      ignore: Option<(usize, usize)>)
+                                      -> Option<Vec<Direction>> {
+  if from == to {
+    return Some(Vec::new());
+  }
+
+  let mut visited = HashSet::new();
+  let mut came_from: HashMap<(usize, usize), ((usize, usize), Direction)> = HashMap::new();
+  let mut queue = VecDeque::new();
+
+  visited.insert(from);
+  queue.push_back(from);
+
+  while let Some(current) = queue.pop_front() {
+    for (direction, neighbor) in neighbors_ignoring(field, current, ignore) {
+      if visited.contains(&neighbor) {
+        continue;
+      }
+
+      visited.insert(neighbor);
+      came_from.insert(neighbor, (current, direction));
+
+      if neighbor == to {
+        return Some(reconstruct_path(&came_from, to));
+      }
+
+      queue.push_back(neighbor);
+    }
+  }
+
+  None
+}
+
+/// Convenience wrapper that returns only the first step of the shortest path.
+pub(crate) fn step_toward(field: &Field, from: (usize, usize), to: (usize, usize)) -> Option<Direction> {
+  step_toward_ignoring(field, from, to, None)
+}
+
+/// Like `step_toward`, but see `shortest_path_ignoring`.
+pub(crate) fn step_toward_ignoring(field: &Field,
+                                    from: (usize, usize),
+                                    to: (usize, usize),
+                                    ignore: Option<(usize, usize)>)
+                                    -> Option<Direction> {
+  shortest_path_ignoring(field, from, to, ignore).and_then(|path| path.into_iter().next())
+}
+
+/// Where stepping `direction` from `from` lands, honoring gate wraparound.
+/// Unlike `neighbors_ignoring`, this doesn't filter out blocked cells, so callers that
+/// need to know whether a move is actually legal must check that themselves
+/// (see `is_open`).
+pub(crate) fn step(field: &Field, from: (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+  raw_neighbor(field, from, direction)
+}
+
+/// Whether a cell can be walked onto: not a wall, not occupied by a bug.
+pub(crate) fn is_open(field: &Field, coords: (usize, usize)) -> bool {
+  is_open_ignoring(field, coords, None)
+}
+
+/// Like `is_open`, but treats `ignore` (if given) as open even if it's
+/// bug-occupied.
+pub(crate) fn is_open_ignoring(field: &Field, coords: (usize, usize), ignore: Option<(usize, usize)>) -> bool {
+  let cell = &field.cells[coords.0][coords.1];
+  !cell.is_inaccessible() && (!cell.has_bug() || Some(coords) == ignore)
+}
+
+/// All cells reachable from `from` in a single legal step, gate wraparound
+/// included, without the `Direction` that got you there. See
+/// `shortest_path_ignoring`.
+pub(crate) fn legal_steps_ignoring(field: &Field,
+                                    from: (usize, usize),
+                                    ignore: Option<(usize, usize)>)
+                                    -> Vec<(usize, usize)> {
+  neighbors_ignoring(field, from, ignore).into_iter().map(|(_, coords)| coords).collect()
+}
+
+fn reconstruct_path(came_from: &HashMap<(usize, usize), ((usize, usize), Direction)>,
+                     to: (usize, usize))
+                     -> Vec<Direction> {
+  let mut directions = Vec::new();
+  let mut current = to;
+
+  while let Some(&(prev, direction)) = came_from.get(&current) {
+    directions.push(direction);
+    current = prev;
+  }
+
+  directions.reverse();
+  directions
+}
+
+fn neighbors_ignoring(field: &Field,
+                       from: (usize, usize),
+                       ignore: Option<(usize, usize)>)
+                       -> Vec<(Direction, (usize, usize))> {
+  [Direction::Up, Direction::Down, Direction::Left, Direction::Right]
+    .iter()
+    .filter_map(|&direction| raw_neighbor(field, from, direction).map(|coords| (direction, coords)))
+    .filter(|&(_, coords)| is_open_ignoring(field, coords, ignore))
+    .collect()
+}
+
+fn raw_neighbor(field: &Field, (row, col): (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+  let height = field.cells.len();
+  let width = field.cells.get(0).map_or(0, |r| r.len());
+  let cell = &field.cells[row][col];
+
+  match direction {
+    Direction::Up if row > 0 => Some((row - 1, col)),
+    Direction::Down if row + 1 < height => Some((row + 1, col)),
+    Direction::Left if col > 0 => Some((row, col - 1)),
+    Direction::Left if width > 0 && cell.is_gate_left() => Some((row, width - 1)),
+    Direction::Right if col + 1 < width => Some((row, col + 1)),
+    Direction::Right if cell.is_gate_right() => Some((row, 0)),
+    _ => None,
+  }
+}
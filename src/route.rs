@@ -0,0 +1,126 @@
+use crate::pathfinding;
+use crate::time_keeper::TimeKeeper;
+use crate::Field;
+
+/// Plans an order to visit every snippet on `field`, starting from `me`:
+/// builds a nearest-neighbor tour from the pairwise BFS distances between
+/// `me` and every snippet, then improves it with a cheap 2-opt pass that
+/// reverses sub-segments whenever doing so shortens the total route length.
+/// The 2-opt pass shares the caller's `time_keeper` rather than budgeting
+/// its own slice of time, so it backs off the moment the turn's real
+/// deadline is close rather than spending a fixed allotment regardless of
+/// what's already elapsed. Returns the ordered snippet coordinates (not
+/// including `me`); callers should pathfind to the first entry and re-plan
+/// each round as snippets are collected or new ones appear.
+pub(crate) fn plan_route(field: &Field, time_keeper: &TimeKeeper) -> Vec<(usize, usize)> {
+  if field.snippets.is_empty() {
+    return Vec::new();
+  }
+
+  let distances = pairwise_distances(field);
+  let mut order = nearest_neighbor_order(&distances, field.snippets.len());
+  two_opt(&distances, &mut order, time_keeper);
+
+  order.into_iter().map(|i| field.snippets[i]).collect()
+}
+
+// Distances between every node, where node 0 is `me` and node `i + 1` is
+// `field.snippets[i]`.
+fn pairwise_distances(field: &Field) -> Vec<Vec<usize>> {
+  let mut nodes = Vec::with_capacity(field.snippets.len() + 1);
+  nodes.push(field.me);
+  nodes.extend_from_slice(&field.snippets);
+
+  nodes.iter()
+    .map(|&from| nodes.iter().map(|&to| distance(field, from, to)).collect())
+    .collect()
+}
+
+fn distance(field: &Field, from: (usize, usize), to: (usize, usize)) -> usize {
+  if from == to {
+    0
+  } else {
+    pathfinding::shortest_path(field, from, to).map_or(usize::MAX, |path| path.len())
+  }
+}
+
+fn nearest_neighbor_order(distances: &[Vec<usize>], snippet_count: usize) -> Vec<usize> {
+  let mut visited = vec![false; snippet_count];
+  let mut order = Vec::with_capacity(snippet_count);
+  let mut current = 0;
+
+  for _ in 0..snippet_count {
+    let next = (1..=snippet_count)
+      .filter(|&node| !visited[node - 1])
+      .min_by_key(|&node| distances[current][node])
+      .unwrap();
+
+    visited[next - 1] = true;
+    order.push(next - 1);
+    current = next;
+  }
+
+  order
+}
+
+fn two_opt(distances: &[Vec<usize>], order: &mut [usize], time_keeper: &TimeKeeper) {
+  let mut improved = true;
+
+  while improved && !time_keeper.is_over() {
+    improved = false;
+
+    for i in 0..order.len() {
+      if time_keeper.is_over() {
+        break;
+      }
+
+      for j in (i + 1)..order.len() {
+        let before = path_length(distances, order);
+        order[i..=j].reverse();
+        let after = path_length(distances, order);
+
+        if after < before {
+          improved = true;
+        } else {
+          order[i..=j].reverse();
+        }
+      }
+    }
+  }
+}
+
+fn path_length(distances: &[Vec<usize>], order: &[usize]) -> usize {
+  let mut total: usize = 0;
+  let mut current = 0;
+
+  for &snippet in order {
+    let node = snippet + 1;
+    total = total.saturating_add(distances[current][node]);
+    current = node;
+  }
+
+  total
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn two_opt_untangles_a_crossed_tour() {
+    // `me` sits at position 0 on a line, and the four snippets sit at 1, 3,
+    // 2, 4. The starting order [A, B, C, D] visits 3 before
+    // backtracking to 2, crossing over itself; swapping B and C (a single
+    // reversal) straightens it into [A, C, B, D].
+    let positions = [0i64, 1, 3, 2, 4];
+    let distances: Vec<Vec<usize>> = positions.iter()
+      .map(|&from| positions.iter().map(|&to| (from - to).unsigned_abs() as usize).collect())
+      .collect();
+
+    let mut order = vec![0, 1, 2, 3];
+    two_opt(&distances, &mut order, &TimeKeeper::new(1000));
+
+    assert_eq!(order, vec![0, 2, 1, 3]);
+    assert_eq!(path_length(&distances, &order), 4);
+  }
+}
@@ -0,0 +1,114 @@
+use crate::pathfinding;
+use crate::{Game, Move};
+
+/// Clones `game` and advances it by one round as if `my_move` were played:
+/// the player steps in the chosen direction (respecting walls and gate
+/// wraparound), banks a snippet on arrival, mine/spawn-point timers tick
+/// down, spawn points release a bug once their timer hits zero, and a
+/// `DropBomb` move leaves a mine on the tile the player is leaving. Other
+/// players and existing bugs are left where they are; predicting their
+/// movement is the danger map's job, not the simulator's.
+pub(crate) fn simulate(game: &Game, my_move: &Move) -> Game {
+  let mut next = game.clone();
+  next.round += 1;
+
+  tick_timers(&mut next);
+  apply_move(&mut next, my_move);
+
+  next
+}
+
+fn apply_move(game: &mut Game, my_move: &Move) {
+  let direction = match my_move {
+    Move::Direction { direction } => *direction,
+    Move::DropBomb { direction, .. } => *direction,
+    Move::Pass => return,
+  };
+
+  let from = game.field.me;
+  let to = match pathfinding::step(&game.field, from, direction) {
+    Some(coords) if pathfinding::is_open(&game.field, coords) => coords,
+    _ => return,
+  };
+
+  let my_bot_id = game.settings.my_bot_id;
+  game.field.cells[from.0][from.1].remove_player(my_bot_id);
+  game.field.cells[to.0][to.1].add_player(my_bot_id);
+  game.field.me = to;
+
+  if game.field.cells[to.0][to.1].has_code_snippet() {
+    game.field.cells[to.0][to.1].remove_code_snippet();
+    game.field.snippets.retain(|&coords| coords != to);
+
+    if let Some(player) = game.players.get_mut(&game.settings.my_bot) {
+      player.collect_snippet();
+    }
+  }
+
+  if let Move::DropBomb { rounds, .. } = my_move {
+    let dropped = game.players.get_mut(&game.settings.my_bot).is_some_and(|player| player.use_bomb());
+
+    if dropped {
+      game.field.cells[from.0][from.1].place_mine(*rounds);
+    }
+  }
+}
+
+fn tick_timers(game: &mut Game) {
+  for row in game.field.cells.iter_mut() {
+    for cell in row.iter_mut() {
+      if cell.tick_timers() {
+        cell.spawn_bug(0);
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::collections::HashMap;
+
+  use super::*;
+  use crate::{parse_field, Direction, Player, Settings};
+
+  fn game_with_field(spec: &str, field_width: usize, field_height: usize) -> Game {
+    let settings = Settings { field_width, field_height, my_bot: "me".to_owned(), my_bot_id: 0, ..Default::default() };
+    let field = parse_field(&settings, spec);
+
+    let mut players = HashMap::new();
+    players.insert(settings.my_bot.clone(), Player { snippets: 0, bombs: 1 });
+
+    Game { settings, round: 0, field, players }
+  }
+
+  #[test]
+  fn simulate_moves_the_player() {
+    let game = game_with_field("P0,.,.", 3, 1);
+
+    let next = simulate(&game, &Move::Direction { direction: Direction::Right });
+
+    assert_eq!(next.field.me, (0, 1));
+  }
+
+  #[test]
+  fn simulate_banks_a_snippet_on_arrival() {
+    let game = game_with_field("P0,C,.", 3, 1);
+
+    let next = simulate(&game, &Move::Direction { direction: Direction::Right });
+
+    assert!(!next.field.cells[0][1].has_code_snippet(), "the snippet should be collected, not left behind");
+    assert!(!next.field.snippets.contains(&(0, 1)));
+    assert_eq!(next.players.get("me").unwrap().snippets(), 1);
+  }
+
+  #[test]
+  fn simulate_drops_a_bomb_on_the_tile_left_behind() {
+    let game = game_with_field("P0,.,.", 3, 1);
+
+    let next = simulate(&game, &Move::DropBomb { direction: Direction::Right, rounds: 5 });
+
+    assert_eq!(next.field.me, (0, 1));
+    assert!(next.field.cells[0][0].has_mine());
+    assert_eq!(next.players.get("me").unwrap().bombs(), 0);
+  }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashSet;
+
+use crate::pathfinding;
+use crate::Field;
+
+/// Each bug's `ai_type` is assumed to select one of three deterministic
+/// behaviors toward the player: 0 chases, 1 flees, anything else is treated
+/// as unpredictable and can step to any legal neighbor.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BugBehavior {
+  Chaser,
+  Fleer,
+  Unknown,
+}
+
+fn behavior_for(ai_type: usize) -> BugBehavior {
+  match ai_type {
+    0 => BugBehavior::Chaser,
+    1 => BugBehavior::Fleer,
+    _ => BugBehavior::Unknown,
+  }
+}
+
+/// Projects every bug on `field` forward `rounds` rounds and returns, for
+/// each round `t` in `0..rounds`, the set of cells a bug could occupy at
+/// that time (round 0 is the bugs' current positions). Consumers only check
+/// whether their own landing cell is in the set for that round; a head-on
+/// swap with a bug that's vacating a cell as you enter it isn't tracked
+/// here, since that needs each bug's individual round-to-round trajectory
+/// rather than the merged per-round occupancy set this returns.
+pub(crate) fn danger_map(field: &Field, rounds: usize) -> Vec<HashSet<(usize, usize)>> {
+  let bugs = find_bugs(field);
+  let mut frontiers: Vec<HashSet<(usize, usize)>> = bugs.iter()
+    .map(|&(pos, _)| {
+      let mut frontier = HashSet::new();
+      frontier.insert(pos);
+      frontier
+    })
+    .collect();
+
+  let mut danger = Vec::with_capacity(rounds);
+
+  for t in 0..rounds {
+    danger.push(frontiers.iter().flatten().cloned().collect());
+
+    if t + 1 == rounds {
+      break;
+    }
+
+    for (frontier, &(start, ai_type)) in frontiers.iter_mut().zip(bugs.iter()) {
+      *frontier = step_frontier(field, frontier, ai_type, start);
+    }
+  }
+
+  danger
+}
+
+// `start` is the bug's own round-0 position. `field` is never mutated as the
+// projection advances, so without excluding `start` from occupancy checks a
+// bug would forever read its own frozen starting cell as bug-occupied and
+// get treated as permanently boxed in by itself.
+fn step_frontier(field: &Field,
+                  frontier: &HashSet<(usize, usize)>,
+                  ai_type: usize,
+                  start: (usize, usize))
+                  -> HashSet<(usize, usize)> {
+  match behavior_for(ai_type) {
+    BugBehavior::Chaser => frontier.iter().map(|&pos| step_chaser(field, pos, start)).collect(),
+    BugBehavior::Fleer => frontier.iter().map(|&pos| step_fleer(field, pos, start)).collect(),
+    BugBehavior::Unknown => {
+      frontier.iter().flat_map(|&pos| pathfinding::legal_steps_ignoring(field, pos, Some(start))).collect()
+    }
+  }
+}
+
+fn step_chaser(field: &Field, pos: (usize, usize), start: (usize, usize)) -> (usize, usize) {
+  pathfinding::step_toward_ignoring(field, pos, field.me, Some(start))
+    .and_then(|direction| pathfinding::step(field, pos, direction))
+    .filter(|&coords| pathfinding::is_open_ignoring(field, coords, Some(start)))
+    .unwrap_or(pos)
+}
+
+fn step_fleer(field: &Field, pos: (usize, usize), start: (usize, usize)) -> (usize, usize) {
+  pathfinding::legal_steps_ignoring(field, pos, Some(start))
+    .into_iter()
+    .max_by_key(|&candidate| distance_from_me(field, candidate, start))
+    .unwrap_or(pos)
+}
+
+fn distance_from_me(field: &Field, pos: (usize, usize), start: (usize, usize)) -> usize {
+  pathfinding::shortest_path_ignoring(field, pos, field.me, Some(start)).map_or(usize::MAX, |path| path.len())
+}
+
+fn find_bugs(field: &Field) -> Vec<((usize, usize), usize)> {
+  let mut bugs = Vec::new();
+
+  for (row, cells) in field.cells.iter().enumerate() {
+    for (col, cell) in cells.iter().enumerate() {
+      for ai_type in cell.bug_ai_types() {
+        bugs.push(((row, col), ai_type));
+      }
+    }
+  }
+
+  bugs
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{parse_field, Settings};
+
+  #[test]
+  fn danger_map_steps_a_chaser_toward_me() {
+    let settings = Settings { field_width: 5, field_height: 1, my_bot_id: 10, ..Default::default() };
+    let field = parse_field(&settings, "E0,.,.,.,P10");
+
+    let danger = danger_map(&field, 2);
+
+    assert!(danger[0].contains(&(0, 0)), "round 0 is the bug's current position");
+    assert!(danger[1].contains(&(0, 1)), "a chaser (ai_type 0) should step toward `me`");
+  }
+}
@@ -0,0 +1,17 @@
+use std::time::{Duration, Instant};
+
+/// Tracks a wall-clock budget for a single turn so an iterative planner can
+/// keep refining its answer without risking a timeout.
+pub(crate) struct TimeKeeper {
+  deadline: Instant,
+}
+
+impl TimeKeeper {
+  pub(crate) fn new(budget_millis: u64) -> TimeKeeper {
+    TimeKeeper { deadline: Instant::now() + Duration::from_millis(budget_millis) }
+  }
+
+  pub(crate) fn is_over(&self) -> bool {
+    Instant::now() >= self.deadline
+  }
+}